@@ -1,11 +1,14 @@
+// Bevy systems routinely take more than 7 query/resource parameters and
+// use compound query tuples; both are idiomatic here, not a smell.
+#![allow(clippy::too_many_arguments, clippy::type_complexity)]
+
 use bevy::{
     prelude::*,
-    sprite::{
-        MaterialMesh2dBundle,
-        collide_aabb::{collide, Collision}
-    },
+    sprite::MaterialMesh2dBundle,
     time::FixedTimestep
 };
+use bevy_rapier2d::prelude::*;
+use rand::Rng;
 
 const WINDOW_WIDTH: f32 = 750.0;
 const WINDOW_HEIGHT: f32 = 450.0;
@@ -33,17 +36,129 @@ const BALL_COLOR: Color = Color::rgb(0.3, 0.3, 0.3);
 
 const SPEED: f32 = 200.0;
 
-#[derive(Default)]
-struct CollisionEvent;
+const WINNING_SCORE: usize = 11;
+const SERVE_DELAY: f32 = 1.0;
+
+const BALL_SPEED: f32 = SPEED * std::f32::consts::SQRT_2;
+const MAX_BOUNCE: f32 = std::f32::consts::FRAC_PI_3;
+const MIN_SERVE_ANGLE: f32 = -std::f32::consts::FRAC_PI_4;
+const MAX_SERVE_ANGLE: f32 = std::f32::consts::FRAC_PI_4;
+
+const SCOREBOARD_FONT_SIZE: f32 = 40.0;
+const SCOREBOARD_PADDING: Val = Val::Px(20.0);
+const SCORE_COLOR: Color = Color::rgb(0.3, 0.3, 0.3);
+
+#[derive(Resource, Default)]
+struct Scoreboard {
+    left: usize,
+    right: usize
+}
+
+#[derive(Clone, Eq, PartialEq, Debug, Hash)]
+enum GameState {
+    Serving,
+    Playing,
+    Paused,
+    GameOver
+}
+
+#[derive(Resource, Deref, DerefMut)]
+struct ServeTimer(Timer);
+
+// Easy/Hard aren't reachable yet — PaddleControl::default() always picks
+// Medium — but the variants are part of the public difficulty scale.
+#[allow(dead_code)]
+#[derive(Clone, Copy, PartialEq)]
+enum Difficulty {
+    Easy,
+    Medium,
+    Hard
+}
+
+impl Difficulty {
+    fn max_speed(self) -> f32 {
+        match self {
+            Difficulty::Easy => SPEED * 0.5,
+            Difficulty::Medium => SPEED * 0.75,
+            Difficulty::Hard => SPEED
+        }
+    }
+
+    fn reaction_error(self) -> f32 {
+        match self {
+            Difficulty::Easy => 40.0,
+            Difficulty::Medium => 20.0,
+            Difficulty::Hard => 5.0
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum Controller {
+    Human,
+    Ai(Difficulty)
+}
+
+#[derive(Resource)]
+struct PaddleControl {
+    left: Controller,
+    right: Controller
+}
+
+impl Default for PaddleControl {
+    fn default() -> Self {
+        PaddleControl {
+            left: Controller::Human,
+            right: Controller::Ai(Difficulty::Medium)
+        }
+    }
+}
 
 #[derive(Component)]
-struct Collider;
+struct AiPaddle {
+    max_speed: f32,
+    reaction_error: f32
+}
+
+#[cfg_attr(not(feature = "bevy_debug_stepping"), allow(dead_code))]
+const STEP_ORDER: [&str; 4] = ["handle_collisions", "move_left_paddle", "move_right_paddle", "ai_paddle"];
+
+// Hand-rolled stand-in for bevy::ecs::schedule::Stepping, which isn't
+// available on the bevy version this crate targets. Pauses the gated
+// systems in STEP_ORDER and lets them be single-stepped one at a time
+// via stepping_allows, rather than integrating the real feature.
+#[derive(Resource, Default)]
+struct DebugStepping {
+    paused: bool,
+    step_frame: bool,
+    step_system: Option<&'static str>,
+    #[cfg_attr(not(feature = "bevy_debug_stepping"), allow(dead_code))]
+    cursor: usize
+}
 
+#[cfg(feature = "bevy_debug_stepping")]
 #[derive(Component)]
-struct Ball;
+struct SteppingPromptText;
+
+fn stepping_allows(name: &'static str, stepping: &DebugStepping) -> bool {
+    if !stepping.paused {
+        return true;
+    }
+
+    match stepping.step_system {
+        Some(target) => target == name,
+        None => stepping.step_frame
+    }
+}
+
+#[derive(Component)]
+struct LeftScoreText;
 
-#[derive(Component, Deref, DerefMut)]
-struct Velocity(Vec2);
+#[derive(Component)]
+struct RightScoreText;
+
+#[derive(Component)]
+struct Ball;
 
 #[derive(Component)]
 struct LeftPaddle;
@@ -89,7 +204,10 @@ impl WallLocation {
 #[derive(Bundle)]
 struct WallBundle {
     sprite_bundle: SpriteBundle,
+    rigid_body: RigidBody,
     collider: Collider,
+    restitution: Restitution,
+    active_events: ActiveEvents,
     side: WallLocation
 }
 
@@ -108,15 +226,21 @@ impl WallBundle {
                 },
                 ..default()
             },
-            collider: Collider,
+            rigid_body: RigidBody::KinematicPositionBased,
+            // Unit shape: bevy_rapier2d scales colliders by the entity's
+            // Transform.scale, same as the paddle/ball colliders below.
+            collider: Collider::cuboid(0.5, 0.5),
+            restitution: Restitution::coefficient(1.0),
+            active_events: ActiveEvents::COLLISION_EVENTS,
             side: location.side()
         }
     }
 }
 
 fn main() {
-    App::new()
-    .add_plugins(DefaultPlugins
+    let mut app = App::new();
+
+    app.add_plugins(DefaultPlugins
         .set(
             WindowPlugin {
                 window: WindowDescriptor {
@@ -129,24 +253,80 @@ fn main() {
             }
         )
     )
+    .add_plugin(RapierPhysicsPlugin::<NoUserData>::pixels_per_meter(100.0))
+    .insert_resource(RapierConfiguration {
+        gravity: Vec2::ZERO,
+        ..default()
+    })
     .insert_resource(ClearColor(BACKGROUND_COLOR))
+    .insert_resource(Scoreboard::default())
+    .insert_resource(PaddleControl::default())
+    .insert_resource(ServeTimer(Timer::from_seconds(SERVE_DELAY, TimerMode::Once)))
+    .insert_resource(DebugStepping::default())
+    .add_state(GameState::Serving)
     .add_startup_system(setup)
-    .add_event::<CollisionEvent>()
+    .add_system_set(
+        SystemSet::on_enter(GameState::Serving)
+        .with_system(reset_serve_timer)
+        .with_system(pause_physics)
+    )
+    .add_system_set(
+        SystemSet::on_update(GameState::Serving)
+        .with_system(serve_countdown)
+    )
+    .add_system_set(
+        SystemSet::on_enter(GameState::Playing)
+        .with_system(resume_physics)
+    )
     .add_system_set(
         SystemSet::new()
         .with_run_criteria(FixedTimestep::step(TIME_STEP as f64))
-        .with_system(check_for_collisions)
-        .with_system(move_ball.before(check_for_collisions))
-        .with_system(move_left_paddle.before(check_for_collisions))
-        .with_system(move_right_paddle.before(check_for_collisions))
+        .with_system(handle_collisions)
+        .with_system(move_left_paddle)
+        .with_system(move_right_paddle)
+        .with_system(ai_paddle)
+        .with_system(
+            consume_debug_step
+                .after(handle_collisions)
+                .after(move_left_paddle)
+                .after(move_right_paddle)
+                .after(ai_paddle)
+        )
+    )
+    .add_system_set(
+        SystemSet::on_update(GameState::Playing)
+        .with_system(toggle_pause)
+    )
+    .add_system_set(
+        SystemSet::on_enter(GameState::Paused)
+        .with_system(pause_physics)
+    )
+    .add_system_set(
+        SystemSet::on_update(GameState::Paused)
+        .with_system(toggle_pause)
+    )
+    .add_system_set(
+        SystemSet::on_enter(GameState::GameOver)
+        .with_system(pause_physics)
     )
-    .run();
+    .add_system_set(
+        SystemSet::on_update(GameState::GameOver)
+        .with_system(restart_on_input)
+    )
+    .add_system(update_scoreboard);
+
+    #[cfg(feature = "bevy_debug_stepping")]
+    app.add_system(toggle_debug_stepping)
+        .add_system(update_stepping_prompt);
+
+    app.run();
 }
 
 fn setup(
     mut commands: Commands,
     mut meshes: ResMut<Assets<Mesh>>,
-    mut materials: ResMut<Assets<ColorMaterial>>
+    mut materials: ResMut<Assets<ColorMaterial>>,
+    paddle_control: Res<PaddleControl>
 ) {
     // Camera
     commands.spawn(Camera2dBundle::default());
@@ -158,7 +338,7 @@ fn setup(
     commands.spawn(WallBundle::new(WallLocation::Bottom));
 
     // Left paddle
-    commands.spawn((
+    let mut left_paddle = commands.spawn((
         SpriteBundle {
             sprite: Sprite {
                 color: PADDLE_LEFT_COLOR,
@@ -172,11 +352,20 @@ fn setup(
             ..default()
         },
         LeftPaddle,
-        Collider
+        RigidBody::KinematicPositionBased,
+        Collider::cuboid(0.5, 0.5),
+        Restitution::coefficient(1.0),
+        ActiveEvents::COLLISION_EVENTS
     ));
+    if let Controller::Ai(difficulty) = paddle_control.left {
+        left_paddle.insert(AiPaddle {
+            max_speed: difficulty.max_speed(),
+            reaction_error: difficulty.reaction_error()
+        });
+    }
 
     // Right paddle
-    commands.spawn((
+    let mut right_paddle = commands.spawn((
         SpriteBundle {
             sprite: Sprite {
                 color: PADDLE_RIGHT_COLOR,
@@ -190,8 +379,17 @@ fn setup(
             ..default()
         },
         RightPaddle,
-        Collider
+        RigidBody::KinematicPositionBased,
+        Collider::cuboid(0.5, 0.5),
+        Restitution::coefficient(1.0),
+        ActiveEvents::COLLISION_EVENTS
     ));
+    if let Controller::Ai(difficulty) = paddle_control.right {
+        right_paddle.insert(AiPaddle {
+            max_speed: difficulty.max_speed(),
+            reaction_error: difficulty.reaction_error()
+        });
+    }
 
     // Ball
     commands.spawn((
@@ -202,63 +400,237 @@ fn setup(
             ..default()
         },
         Ball,
-        Velocity(Vec2::new(1.0, 1.0)),
-        Collider
+        RigidBody::Dynamic,
+        Collider::ball(0.5),
+        Restitution::coefficient(1.0),
+        Velocity::linear(random_serve_velocity()),
+        ActiveEvents::COLLISION_EVENTS
+    ));
+
+    // Scoreboard
+    commands.spawn((
+        TextBundle::from_sections([
+            TextSection::new(
+                "0",
+                TextStyle {
+                    font_size: SCOREBOARD_FONT_SIZE,
+                    color: SCORE_COLOR,
+                    ..default()
+                }
+            )
+        ])
+        .with_style(Style {
+            position_type: PositionType::Absolute,
+            position: UiRect {
+                top: SCOREBOARD_PADDING,
+                left: SCOREBOARD_PADDING * 5.0,
+                ..default()
+            },
+            ..default()
+        }),
+        LeftScoreText
+    ));
+
+    commands.spawn((
+        TextBundle::from_sections([
+            TextSection::new(
+                "0",
+                TextStyle {
+                    font_size: SCOREBOARD_FONT_SIZE,
+                    color: SCORE_COLOR,
+                    ..default()
+                }
+            )
+        ])
+        .with_style(Style {
+            position_type: PositionType::Absolute,
+            position: UiRect {
+                top: SCOREBOARD_PADDING,
+                right: SCOREBOARD_PADDING * 5.0,
+                ..default()
+            },
+            ..default()
+        }),
+        RightScoreText
+    ));
+
+    // Stepping prompt
+    #[cfg(feature = "bevy_debug_stepping")]
+    commands.spawn((
+        TextBundle::from_section(
+            stepping_prompt_text(&DebugStepping::default()),
+            TextStyle {
+                font_size: 16.0,
+                color: SCORE_COLOR,
+                ..default()
+            }
+        )
+        .with_style(Style {
+            position_type: PositionType::Absolute,
+            position: UiRect {
+                bottom: SCOREBOARD_PADDING,
+                left: SCOREBOARD_PADDING,
+                ..default()
+            },
+            ..default()
+        }),
+        SteppingPromptText
     ));
 }
 
-fn check_for_collisions(
-    mut ball_query: Query<(&mut Velocity, &Transform), With<Ball>>,
-    collider_query: Query<(&Transform, Option<&WallLocation>), With<Collider>>,
-    mut collision_events: EventWriter<CollisionEvent>
+fn reset_serve_timer(mut serve_timer: ResMut<ServeTimer>) {
+    serve_timer.reset();
+}
+
+fn pause_physics(mut rapier_config: ResMut<RapierConfiguration>) {
+    rapier_config.physics_pipeline_active = false;
+}
+
+fn resume_physics(mut rapier_config: ResMut<RapierConfiguration>) {
+    rapier_config.physics_pipeline_active = true;
+}
+
+fn serve_countdown(
+    time: Res<Time>,
+    mut serve_timer: ResMut<ServeTimer>,
+    mut state: ResMut<State<GameState>>
 ) {
-    let (mut ball_velocity, ball_transform) = ball_query.single_mut();
+    if serve_timer.tick(time.delta()).finished() {
+        state.set(GameState::Playing).ok();
+    }
+}
 
-    for (collider_transform, wall_location) in &collider_query {
-        let collision = collide(
-            ball_transform.translation, ball_transform.scale.truncate(),
-            collider_transform.translation, collider_transform.scale.truncate()
-        );
+fn toggle_pause(
+    keyboard_input: Res<Input<KeyCode>>,
+    mut state: ResMut<State<GameState>>
+) {
+    if !keyboard_input.just_pressed(KeyCode::P) {
+        return;
+    }
 
-        if let Some(collision) = collision {
-            collision_events.send_default();
+    match state.current() {
+        GameState::Playing => state.set(GameState::Paused).ok(),
+        GameState::Paused => state.set(GameState::Playing).ok(),
+        _ => None
+    };
+}
 
-            if wall_location.is_some() {
-                match wall_location {
-                    Some(WallLocation::Left) => println!("true"),
-                    Some(WallLocation::Right) => println!("true"),
-                    _ => println!("false"),
-                }
-            }
+fn restart_on_input(
+    keyboard_input: Res<Input<KeyCode>>,
+    mut scoreboard: ResMut<Scoreboard>,
+    mut state: ResMut<State<GameState>>,
+    mut ball_query: Query<(&mut Transform, &mut Velocity), With<Ball>>
+) {
+    if !keyboard_input.just_pressed(KeyCode::Return) {
+        return;
+    }
 
-            let mut reflect_x = false;
-            let mut reflect_y = false;
+    let Ok((mut ball_transform, mut ball_velocity)) = ball_query.get_single_mut() else {
+        return;
+    };
 
-            match collision {
-                Collision::Left => reflect_x = ball_velocity.x > 0.0,
-                Collision::Right => reflect_x = ball_velocity.x < 0.0,
-                Collision::Top => reflect_y = ball_velocity.y < 0.0,
-                Collision::Bottom => reflect_y = ball_velocity.y > 0.0,
-                Collision::Inside => {},
-            }
+    scoreboard.left = 0;
+    scoreboard.right = 0;
+    serve_ball(&mut ball_transform, &mut ball_velocity);
+    state.set(GameState::Serving).ok();
+}
 
-            if reflect_x {
-                ball_velocity.x *= -1.0;
-            }
-            if reflect_y {
-                ball_velocity.y *= -1.0;
+fn handle_collisions(
+    mut collision_events: EventReader<CollisionEvent>,
+    mut scoreboard: ResMut<Scoreboard>,
+    mut state: ResMut<State<GameState>>,
+    stepping: Res<DebugStepping>,
+    ball_entity_query: Query<Entity, With<Ball>>,
+    mut ball_query: Query<(&mut Transform, &mut Velocity), With<Ball>>,
+    wall_query: Query<&WallLocation>,
+    paddle_query: Query<(&Transform, Option<&LeftPaddle>, Option<&RightPaddle>), Without<Ball>>
+) {
+    if *state.current() != GameState::Playing || !stepping_allows("handle_collisions", &stepping) {
+        return;
+    }
+
+    let Ok(ball_entity) = ball_entity_query.get_single() else {
+        return;
+    };
+
+    for event in collision_events.iter() {
+        let CollisionEvent::Started(entity_a, entity_b, _flags) = event else {
+            continue;
+        };
+
+        let other = if *entity_a == ball_entity {
+            *entity_b
+        } else if *entity_b == ball_entity {
+            *entity_a
+        } else {
+            continue;
+        };
+
+        if let Ok(wall_location) = wall_query.get(other) {
+            match wall_location {
+                WallLocation::Left => {
+                    scoreboard.right += 1;
+                    if scoreboard.right >= WINNING_SCORE {
+                        state.set(GameState::GameOver).ok();
+                    } else if let Ok((mut ball_transform, mut ball_velocity)) = ball_query.get_single_mut() {
+                        serve_ball(&mut ball_transform, &mut ball_velocity);
+                        state.set(GameState::Serving).ok();
+                    }
+                },
+                WallLocation::Right => {
+                    scoreboard.left += 1;
+                    if scoreboard.left >= WINNING_SCORE {
+                        state.set(GameState::GameOver).ok();
+                    } else if let Ok((mut ball_transform, mut ball_velocity)) = ball_query.get_single_mut() {
+                        serve_ball(&mut ball_transform, &mut ball_velocity);
+                        state.set(GameState::Serving).ok();
+                    }
+                },
+                WallLocation::Top | WallLocation::Bottom => {}
             }
+            continue;
+        }
+
+        if let Ok((paddle_transform, left_paddle, _right_paddle)) = paddle_query.get(other) {
+            let Ok((ball_transform, mut ball_velocity)) = ball_query.get_single_mut() else {
+                continue;
+            };
+
+            let rel = ((ball_transform.translation.y - paddle_transform.translation.y)
+                / (PADDLE_SIZE.y / 2.0))
+                .clamp(-1.0, 1.0);
+            let theta = rel * MAX_BOUNCE;
+            let dir_x = if left_paddle.is_some() { 1.0 } else { -1.0 };
+
+            ball_velocity.linvel = BALL_SPEED * Vec2::new(dir_x * theta.cos(), theta.sin());
         }
     }
 }
 
-fn move_ball(
-    mut query: Query<(&mut Transform, &Velocity)>
+fn serve_ball(transform: &mut Transform, velocity: &mut Velocity) {
+    transform.translation.x = 0.0;
+    transform.translation.y = 0.0;
+    velocity.linvel = random_serve_velocity();
+}
+
+fn random_serve_velocity() -> Vec2 {
+    let mut rng = rand::thread_rng();
+    let theta = rng.gen_range(MIN_SERVE_ANGLE..=MAX_SERVE_ANGLE);
+    let dir_x = if rng.gen_bool(0.5) { 1.0 } else { -1.0 };
+
+    BALL_SPEED * Vec2::new(dir_x * theta.cos(), theta.sin())
+}
+
+fn update_scoreboard(
+    scoreboard: Res<Scoreboard>,
+    mut left_query: Query<&mut Text, (With<LeftScoreText>, Without<RightScoreText>)>,
+    mut right_query: Query<&mut Text, (With<RightScoreText>, Without<LeftScoreText>)>
 ) {
-    for (mut transform, velocity) in &mut query {
-        transform.translation.x += velocity.x * TIME_STEP * SPEED;
-        transform.translation.y += velocity.y * TIME_STEP * SPEED;
-    }
+    let mut left_text = left_query.single_mut();
+    left_text.sections[0].value = scoreboard.left.to_string();
+
+    let mut right_text = right_query.single_mut();
+    right_text.sections[0].value = scoreboard.right.to_string();
 }
 
 fn paddle_movement(
@@ -277,21 +649,156 @@ fn paddle_movement(
     let top_bound = TOP_WALL - WALL_THINKNESS / 2.0 - PADDLE_SIZE.y / 2.0;
     let bottom_bound = BOTTOM_WALL + WALL_THINKNESS / 2.0 + PADDLE_SIZE.y / 2.0;
 
-    transform.translation.y = new_translation.clamp(bottom_bound, top_bound);    
+    transform.translation.y = new_translation.clamp(bottom_bound, top_bound);
 }
 
 fn move_left_paddle(
     keyboard_input: Res<Input<KeyCode>>,
-    mut query: Query<&mut Transform, With<LeftPaddle>>
+    state: Res<State<GameState>>,
+    stepping: Res<DebugStepping>,
+    mut query: Query<&mut Transform, (With<LeftPaddle>, Without<AiPaddle>)>
 ) {
-    let mut transform = query.single_mut();
+    if *state.current() != GameState::Playing || !stepping_allows("move_left_paddle", &stepping) {
+        return;
+    }
+
+    let Ok(mut transform) = query.get_single_mut() else {
+        return;
+    };
     paddle_movement(keyboard_input, &mut transform, KeyCode::W, KeyCode::S);
 }
 
 fn move_right_paddle(
     keyboard_input: Res<Input<KeyCode>>,
-    mut query: Query<&mut Transform, With<RightPaddle>>
+    state: Res<State<GameState>>,
+    stepping: Res<DebugStepping>,
+    mut query: Query<&mut Transform, (With<RightPaddle>, Without<AiPaddle>)>
 ) {
-    let mut transform = query.single_mut();
+    if *state.current() != GameState::Playing || !stepping_allows("move_right_paddle", &stepping) {
+        return;
+    }
+
+    let Ok(mut transform) = query.get_single_mut() else {
+        return;
+    };
     paddle_movement(keyboard_input, &mut transform, KeyCode::Up, KeyCode::Down);
-}
\ No newline at end of file
+}
+
+fn predict_ball_y(ball_pos: Vec2, ball_vel: Vec2, paddle_x: f32) -> f32 {
+    if ball_vel.x.abs() < f32::EPSILON {
+        return ball_pos.y;
+    }
+
+    let time_to_paddle = (paddle_x - ball_pos.x) / ball_vel.x;
+    if time_to_paddle < 0.0 {
+        return ball_pos.y;
+    }
+
+    let y = ball_pos.y + ball_vel.y * time_to_paddle;
+
+    let top_bound = TOP_WALL - WALL_THINKNESS / 2.0;
+    let bottom_bound = BOTTOM_WALL + WALL_THINKNESS / 2.0;
+    let span = top_bound - bottom_bound;
+
+    if span <= 0.0 {
+        return y;
+    }
+
+    // Reflect the predicted y back and forth between the walls, like a triangle wave.
+    let mut offset = (y - bottom_bound) % (2.0 * span);
+    if offset < 0.0 {
+        offset += 2.0 * span;
+    }
+
+    if offset <= span {
+        bottom_bound + offset
+    } else {
+        bottom_bound + (2.0 * span - offset)
+    }
+}
+
+fn ai_paddle(
+    state: Res<State<GameState>>,
+    stepping: Res<DebugStepping>,
+    ball_query: Query<(&Transform, &Velocity), With<Ball>>,
+    mut paddle_query: Query<(&mut Transform, &AiPaddle), Without<Ball>>
+) {
+    if *state.current() != GameState::Playing || !stepping_allows("ai_paddle", &stepping) {
+        return;
+    }
+
+    let Ok((ball_transform, ball_velocity)) = ball_query.get_single() else {
+        return;
+    };
+
+    let top_bound = TOP_WALL - WALL_THINKNESS / 2.0 - PADDLE_SIZE.y / 2.0;
+    let bottom_bound = BOTTOM_WALL + WALL_THINKNESS / 2.0 + PADDLE_SIZE.y / 2.0;
+
+    for (mut paddle_transform, ai) in &mut paddle_query {
+        let predicted_y = predict_ball_y(
+            ball_transform.translation.truncate(),
+            ball_velocity.linvel,
+            paddle_transform.translation.x
+        );
+        let target = (predicted_y + ai.reaction_error).clamp(bottom_bound, top_bound);
+
+        let max_step = ai.max_speed * TIME_STEP;
+        let delta = (target - paddle_transform.translation.y).clamp(-max_step, max_step);
+
+        paddle_transform.translation.y += delta;
+    }
+}
+
+fn consume_debug_step(mut stepping: ResMut<DebugStepping>) {
+    stepping.step_frame = false;
+    stepping.step_system = None;
+}
+
+#[cfg(feature = "bevy_debug_stepping")]
+fn toggle_debug_stepping(
+    keyboard_input: Res<Input<KeyCode>>,
+    mut stepping: ResMut<DebugStepping>
+) {
+    if keyboard_input.just_pressed(KeyCode::F5) {
+        stepping.paused = !stepping.paused;
+    }
+
+    if !stepping.paused {
+        return;
+    }
+
+    if keyboard_input.just_pressed(KeyCode::F6) {
+        stepping.step_frame = true;
+    }
+
+    if keyboard_input.just_pressed(KeyCode::F7) {
+        let cursor = stepping.cursor;
+        stepping.step_system = Some(STEP_ORDER[cursor]);
+        stepping.cursor = (cursor + 1) % STEP_ORDER.len();
+    }
+}
+
+#[cfg(feature = "bevy_debug_stepping")]
+fn stepping_prompt_text(stepping: &DebugStepping) -> String {
+    let order = STEP_ORDER.join(" -> ");
+
+    if !stepping.paused {
+        format!("[F5] pause stepping  |  order: {order}")
+    } else {
+        let next = STEP_ORDER[stepping.cursor];
+        format!(
+            "PAUSED  [F5] resume  [F6] step frame  [F7] step system (next: {next})  |  order: {order}"
+        )
+    }
+}
+
+#[cfg(feature = "bevy_debug_stepping")]
+fn update_stepping_prompt(
+    stepping: Res<DebugStepping>,
+    mut query: Query<&mut Text, With<SteppingPromptText>>
+) {
+    let Ok(mut text) = query.get_single_mut() else {
+        return;
+    };
+    text.sections[0].value = stepping_prompt_text(&stepping);
+}